@@ -0,0 +1,172 @@
+//! Minimal reader over the Fuel ABI JSON emitted under `out/release`.
+//!
+//! Only the surface the client generators need is modelled — the function list
+//! and each function's resolved input/output type names. Parsing goes through
+//! `serde_json::Value` so both the current (`concreteTypes`) and older (`types`)
+//! ABI layouts are tolerated without committing to one exact schema.
+
+use anyhow::Result;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A parsed ABI: the callable functions of a contract.
+pub struct Abi {
+    pub functions: Vec<Function>,
+}
+
+/// A single ABI function with its resolved parameter and return types.
+pub struct Function {
+    pub name: String,
+    pub inputs: Vec<Param>,
+    pub output: String,
+}
+
+/// A named function parameter carrying its resolved Sway type name.
+pub struct Param {
+    pub name: String,
+    pub type_name: String,
+}
+
+/// Parse the functions out of an ABI JSON document.
+pub fn parse(json: &Value) -> Result<Abi> {
+    let types = type_table(json);
+    let resolve = |entry: &Value| resolve_type(entry, &types);
+
+    let mut functions = Vec::new();
+    if let Some(items) = json.get("functions").and_then(Value::as_array) {
+        for item in items {
+            let name = item
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let inputs = item
+                .get("inputs")
+                .and_then(Value::as_array)
+                .map(|args| {
+                    args.iter()
+                        .map(|arg| Param {
+                            name: arg
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .unwrap_or("arg")
+                                .to_string(),
+                            type_name: resolve(arg),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let output = item
+                .get("output")
+                .map(&resolve)
+                .unwrap_or_else(|| "()".to_string());
+            functions.push(Function {
+                name,
+                inputs,
+                output,
+            });
+        }
+    }
+    Ok(Abi { functions })
+}
+
+/// Build a map from type id to its Sway type string across both ABI layouts.
+fn type_table(json: &Value) -> HashMap<String, String> {
+    let mut types = HashMap::new();
+    if let Some(items) = json.get("concreteTypes").and_then(Value::as_array) {
+        for item in items {
+            if let (Some(id), Some(ty)) = (
+                item.get("concreteTypeId").and_then(Value::as_str),
+                item.get("type").and_then(Value::as_str),
+            ) {
+                types.insert(id.to_string(), ty.to_string());
+            }
+        }
+    }
+    if let Some(items) = json.get("types").and_then(Value::as_array) {
+        for item in items {
+            if let (Some(id), Some(ty)) = (
+                item.get("typeId"),
+                item.get("type").and_then(Value::as_str),
+            ) {
+                types.insert(id.to_string(), ty.to_string());
+            }
+        }
+    }
+    types
+}
+
+/// Resolve a function input/output entry to its Sway type string.
+fn resolve_type(entry: &Value, types: &HashMap<String, String>) -> String {
+    if let Some(id) = entry.get("concreteTypeId").and_then(Value::as_str) {
+        if let Some(ty) = types.get(id) {
+            return ty.clone();
+        }
+    }
+    if let Some(ty) = entry.get("type").and_then(Value::as_str) {
+        return ty.to_string();
+    }
+    if let Some(id) = entry.get("typeId") {
+        let key = id.to_string();
+        return types.get(&key).cloned().unwrap_or(key);
+    }
+    "()".to_string()
+}
+
+/// Map a Sway type string to its idiomatic TypeScript type, preserving the
+/// wrapper types the hand-written tests rely on.
+pub fn ts_type(sway: &str) -> String {
+    if let Some(inner) = option_inner(sway) {
+        return format!("{} | undefined", ts_type(inner));
+    }
+    match simple_name(sway) {
+        "bool" => "boolean".to_string(),
+        "u8" | "u16" | "u32" | "u64" | "u256" => "BigNumberish".to_string(),
+        "b256" => "string".to_string(),
+        "AssetId" => "AssetId".to_string(),
+        "SubId" => "SubId".to_string(),
+        "Bits256" => "Bits256".to_string(),
+        "Identity" => "IdentityInput".to_string(),
+        "String" | "str" => "string".to_string(),
+        "()" => "void".to_string(),
+        _ => "any".to_string(),
+    }
+}
+
+/// Map a Sway type string to its idiomatic Python type.
+pub fn py_type(sway: &str) -> String {
+    if let Some(inner) = option_inner(sway) {
+        return format!("Optional[{}]", py_type(inner));
+    }
+    match simple_name(sway) {
+        "bool" => "bool".to_string(),
+        "u8" | "u16" | "u32" | "u64" | "u256" => "int".to_string(),
+        "b256" => "str".to_string(),
+        "AssetId" => "AssetId".to_string(),
+        "SubId" => "SubId".to_string(),
+        "Bits256" => "Bits256".to_string(),
+        "Identity" => "Identity".to_string(),
+        "String" | "str" => "str".to_string(),
+        "()" => "None".to_string(),
+        _ => "Any".to_string(),
+    }
+}
+
+/// Return the inner type of an `Option<T>`/`enum std::option::Option<T>`.
+fn option_inner(sway: &str) -> Option<&str> {
+    let start = sway.find("Option<")? + "Option<".len();
+    let end = sway.rfind('>')?;
+    (end > start).then(|| sway[start..end].trim())
+}
+
+/// Reduce a fully-qualified Sway type to its final path segment, e.g.
+/// `struct std::asset_id::AssetId` -> `AssetId`.
+fn simple_name(sway: &str) -> &str {
+    sway.trim()
+        .trim_start_matches("struct ")
+        .trim_start_matches("enum ")
+        .rsplit("::")
+        .next()
+        .unwrap_or(sway)
+        .trim()
+}
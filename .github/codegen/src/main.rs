@@ -0,0 +1,298 @@
+//! Client codegen subsystem.
+//!
+//! Discovers the example `*-abi.json` artifacts — the same files `abigen!`
+//! consumes in the test setups — and emits idiomatic, typed TypeScript and
+//! Python client stubs so dapp developers can call a deployed standard-compliant
+//! contract without hand-translating the ABI. One package is written per
+//! standard under the output directory (default `clients/`).
+
+mod abi;
+
+use abi::{Abi, Function};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() -> Result<()> {
+    let examples_dir = env::current_dir()?.join("examples");
+    let out_dir = env::current_dir()?.join("clients");
+
+    let projects = find_sway_projects(&examples_dir)?;
+    if projects.is_empty() {
+        println!("No standards found under {}.", examples_dir.display());
+        return Ok(());
+    }
+
+    for project in projects {
+        let standard = project
+            .file_name()
+            .and_then(|s| s.to_str())
+            .context("Could not get standard name from path")?
+            .to_string();
+
+        for abi_path in find_abi_files(&project)? {
+            let contract = contract_name(&abi_path);
+            println!("Generating clients for {} ({})", standard, contract);
+
+            let json: Value = serde_json::from_str(&fs::read_to_string(&abi_path)?)
+                .with_context(|| format!("Failed to parse ABI at {}", abi_path.display()))?;
+            let parsed = abi::parse(&json)?;
+
+            let package_dir = out_dir.join(&standard);
+            write_typescript(&package_dir, &contract, &parsed)?;
+            write_python(&package_dir, &contract, &parsed)?;
+        }
+    }
+
+    println!("Clients written to {}.", out_dir.display());
+    Ok(())
+}
+
+/// Locate the standard project directories, mirroring the publisher's walk:
+/// immediate children of `directory` whose names start with `src`.
+fn find_sway_projects(directory: &Path) -> Result<Vec<PathBuf>> {
+    let mut projects = vec![];
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() && entry.file_name().to_string_lossy().starts_with("src") {
+            projects.push(path);
+        }
+    }
+    Ok(projects)
+}
+
+/// Recursively collect every `out/release/*-abi.json` under a standard project.
+fn find_abi_files(project: &Path) -> Result<Vec<PathBuf>> {
+    let mut abis = vec![];
+    collect_abis(project, &mut abis)?;
+    abis.sort();
+    Ok(abis)
+}
+
+fn collect_abis(dir: &Path, abis: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_abis(&path, abis)?;
+        } else if path.ends_with_abi_json() {
+            abis.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Small helper trait so the directory walk reads naturally.
+trait AbiPath {
+    fn ends_with_abi_json(&self) -> bool;
+}
+
+impl AbiPath for PathBuf {
+    fn ends_with_abi_json(&self) -> bool {
+        self.file_name()
+            .and_then(|s| s.to_str())
+            .map(|name| name.ends_with("-abi.json"))
+            .unwrap_or(false)
+            && self
+                .parent()
+                .map(|p| p.ends_with("out/release"))
+                .unwrap_or(false)
+    }
+}
+
+/// Derive a PascalCase contract name from the ABI file stem, e.g.
+/// `single_src20_asset-abi.json` -> `SingleSrc20Asset`.
+fn contract_name(abi_path: &Path) -> String {
+    let stem = abi_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .and_then(|name| name.strip_suffix("-abi.json"))
+        .unwrap_or("Contract");
+    pascal_case(stem)
+}
+
+fn write_typescript(package_dir: &Path, contract: &str, parsed: &Abi) -> Result<()> {
+    let ts_dir = package_dir.join("typescript");
+    fs::create_dir_all(&ts_dir)?;
+
+    let mut out = String::new();
+    out.push_str("// Generated by the sway-standards codegen subsystem. Do not edit by hand.\n");
+    out.push_str(
+        "import type { BigNumberish } from 'fuels';\nimport type { AssetId, SubId, Bits256, IdentityInput } from './types';\n\n",
+    );
+    out.push_str(&format!("export class {}Client {{\n", contract));
+    out.push_str("  constructor(private readonly contract: any) {}\n\n");
+    for function in &parsed.functions {
+        out.push_str(&ts_method(function));
+    }
+    out.push_str("}\n");
+    fs::write(ts_dir.join(format!("{}.ts", contract)), out)?;
+
+    // Emit the shared wrapper types so the generated package is self-contained
+    // and the `./types` import above resolves.
+    fs::write(ts_dir.join("types.ts"), TYPES_TS)?;
+
+    // Make the output a package so it can be consumed directly.
+    fs::write(
+        ts_dir.join("package.json"),
+        format!(
+            "{{\n  \"name\": \"@fuel-standards/{}\",\n  \"version\": \"0.0.0\",\n  \"main\": \"{}.ts\"\n}}\n",
+            contract.to_lowercase(),
+            contract
+        ),
+    )?;
+    Ok(())
+}
+
+/// Shared wrapper types imported by every generated TypeScript client. Mirrors
+/// the `AssetId`/`Bits256`/`SubId` plumbing the hand-written tests use.
+const TYPES_TS: &str = "\
+// Generated by the sway-standards codegen subsystem. Do not edit by hand.
+import type { BigNumberish } from 'fuels';
+
+export type Bits256 = string;
+export type SubId = Bits256;
+
+export interface AssetId {
+  bits: Bits256;
+}
+
+export type IdentityInput =
+  | { Address: { bits: Bits256 } }
+  | { ContractId: { bits: Bits256 } };
+
+export type { BigNumberish };
+";
+
+fn ts_method(function: &Function) -> String {
+    let method = camel_case(&function.name);
+    let params: Vec<String> = function
+        .inputs
+        .iter()
+        .map(|p| format!("{}: {}", camel_case(&p.name), abi::ts_type(&p.type_name)))
+        .collect();
+    let ret = abi::ts_type(&function.output);
+    let args: Vec<String> = function
+        .inputs
+        .iter()
+        .map(|p| camel_case(&p.name))
+        .collect();
+
+    // mint/burn create new coins, so thread through a variable-output policy.
+    let policy = if is_mint_or_burn(&function.name) {
+        "\n      .txParams({ variableOutputs: 1 })"
+    } else {
+        ""
+    };
+
+    format!(
+        "  async {}({}): Promise<{}> {{\n    return this.contract.functions\n      .{}({}){}\n      .call();\n  }}\n\n",
+        method,
+        params.join(", "),
+        ret,
+        function.name,
+        args.join(", "),
+        policy,
+    )
+}
+
+fn write_python(package_dir: &Path, contract: &str, parsed: &Abi) -> Result<()> {
+    let py_dir = package_dir.join("python");
+    fs::create_dir_all(&py_dir)?;
+
+    let mut out = String::new();
+    out.push_str("# Generated by the sway-standards codegen subsystem. Do not edit by hand.\n");
+    out.push_str("from typing import Any, Optional\n\n");
+    out.push_str("from fuels import AssetId, SubId, Bits256, Identity\n\n\n");
+    out.push_str(&format!("class {}Client:\n", contract));
+    out.push_str("    def __init__(self, contract: Any) -> None:\n");
+    out.push_str("        self._contract = contract\n\n");
+    for function in &parsed.functions {
+        out.push_str(&py_method(function));
+    }
+    fs::write(py_dir.join(format!("{}.py", snake_case(contract))), out)?;
+    fs::write(
+        py_dir.join("__init__.py"),
+        format!("from .{} import {}Client\n", snake_case(contract), contract),
+    )?;
+    Ok(())
+}
+
+fn py_method(function: &Function) -> String {
+    let params: Vec<String> = function
+        .inputs
+        .iter()
+        .map(|p| format!("{}: {}", snake_case(&p.name), abi::py_type(&p.type_name)))
+        .collect();
+    let ret = abi::py_type(&function.output);
+    let args: Vec<String> = function.inputs.iter().map(|p| snake_case(&p.name)).collect();
+
+    let policy = if is_mint_or_burn(&function.name) {
+        ".with_variable_output_policy(1)"
+    } else {
+        ""
+    };
+
+    let mut signature = String::from("self");
+    if !params.is_empty() {
+        signature.push_str(", ");
+        signature.push_str(&params.join(", "));
+    }
+
+    format!(
+        "    def {}({}) -> {}:\n        return self._contract.functions.{}({}){}.call()\n\n",
+        snake_case(&function.name),
+        signature,
+        ret,
+        function.name,
+        args.join(", "),
+        policy,
+    )
+}
+
+fn is_mint_or_burn(name: &str) -> bool {
+    matches!(name, "mint" | "burn")
+}
+
+fn pascal_case(input: &str) -> String {
+    input
+        .split(['_', '-'])
+        .filter(|s| !s.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn camel_case(input: &str) -> String {
+    let pascal = pascal_case(input);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn snake_case(input: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in input.chars().enumerate() {
+        if ch == '-' {
+            out.push('_');
+        } else if ch.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
@@ -0,0 +1,123 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Name of the on-disk configuration file read from the current working directory.
+pub const CONFIG_FILE_NAME: &str = "publish.toml";
+
+/// Top-level `publish.toml` document: a set of named registry profiles plus the
+/// profile selected when `--profile` is omitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    /// Profile used when the caller does not pass `--profile`.
+    pub default_profile: String,
+    /// Named profiles keyed by the name given to `--profile`.
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A single registry target and the defaults applied when publishing against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    /// Registry the `forc publish` invocations point at.
+    pub registry_url: String,
+    /// Environment variable the publish token is read from. Defaults to
+    /// `FORC_PUB_TOKEN` when omitted so existing setups keep working.
+    #[serde(default = "default_token_env")]
+    pub token_env: String,
+    /// When true, a package whose version already exists on the registry is
+    /// treated as success rather than an error.
+    #[serde(default = "default_true")]
+    pub skip_existing: bool,
+}
+
+fn default_token_env() -> String {
+    "FORC_PUB_TOKEN".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Config {
+    /// Read the config from `path`, or, when no file exists, walk the caller
+    /// through [`initial_setup`] and persist the result.
+    pub fn load_or_init(path: &Path) -> Result<Self> {
+        match read_config(path)? {
+            Some(config) => Ok(config),
+            None => initial_setup(path),
+        }
+    }
+
+    /// Look up a profile by name, resolving `None` to [`Config::default_profile`].
+    pub fn profile(&self, name: Option<&str>) -> Result<&Profile> {
+        let name = name.unwrap_or(&self.default_profile);
+        self.profiles.get(name).ok_or_else(|| {
+            let known = self.profiles.keys().cloned().collect::<Vec<_>>().join(", ");
+            anyhow!("Unknown profile '{}'. Available profiles: {}", name, known)
+        })
+    }
+}
+
+/// Parse the config at `path`, returning `None` when the file does not exist.
+pub fn read_config(path: &Path) -> Result<Option<Config>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config at {}", path.display()))?;
+    let config: Config = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse config at {}", path.display()))?;
+    Ok(Some(config))
+}
+
+/// Prompt the user for a registry URL and token source, then write out an
+/// initial single-profile `publish.toml` and return it.
+pub fn initial_setup(path: &Path) -> Result<Config> {
+    println!(
+        "No {} found. Let's create one.",
+        path.file_name().and_then(|s| s.to_str()).unwrap_or(CONFIG_FILE_NAME)
+    );
+
+    let profile_name = prompt("Profile name", "local")?;
+    let registry_url = prompt("Registry URL", "http://localhost:8080")?;
+    let token_env = prompt("Token environment variable", "FORC_PUB_TOKEN")?;
+
+    let mut profiles = BTreeMap::new();
+    profiles.insert(
+        profile_name.clone(),
+        Profile {
+            registry_url,
+            token_env,
+            skip_existing: true,
+        },
+    );
+
+    let config = Config {
+        default_profile: profile_name,
+        profiles,
+    };
+
+    let rendered = toml::to_string_pretty(&config).context("Failed to serialize initial config")?;
+    fs::write(path, rendered)
+        .with_context(|| format!("Failed to write config to {}", path.display()))?;
+    println!("Wrote {}.", path.display());
+
+    Ok(config)
+}
+
+/// Read a single line from stdin, falling back to `default` on an empty answer.
+fn prompt(label: &str, default: &str) -> Result<String> {
+    print!("{} [{}]: ", label, default);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let trimmed = line.trim();
+    Ok(if trimmed.is_empty() {
+        default.to_string()
+    } else {
+        trimmed.to_string()
+    })
+}
@@ -1,5 +1,11 @@
-use anyhow::{anyhow, Context, Result};
-use petgraph::algo::toposort;
+mod config;
+mod registry;
+mod state;
+
+use anyhow::{anyhow, bail, Context, Result};
+use config::{Config, Profile, CONFIG_FILE_NAME};
+use semver::Version;
+use state::{PublishState, STATE_FILE_NAME};
 use petgraph::graph::DiGraph;
 use petgraph::visit::Dfs;
 use std::collections::{BTreeMap, HashMap, HashSet};
@@ -9,19 +15,96 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use toml_edit::DocumentMut;
 
-fn main() -> Result<()> {
-    if env::var("FORC_PUB_TOKEN").is_err() {
-        return Err(anyhow!(
-            "Error: FORC_PUB_TOKEN environment variable is not set."
-        ));
+/// Command-line options, split out from the positional seed project names so new
+/// flags can be threaded through without reshuffling the publish pipeline.
+/// Which semver component `--bump` increments when a package's version already
+/// exists on the target registry.
+#[derive(Clone, Copy)]
+enum BumpLevel {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl BumpLevel {
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "major" => Ok(BumpLevel::Major),
+            "minor" => Ok(BumpLevel::Minor),
+            "patch" => Ok(BumpLevel::Patch),
+            other => Err(anyhow!(
+                "Invalid --bump value '{}'; expected major, minor, or patch",
+                other
+            )),
+        }
     }
+}
+
+struct Args {
+    profile: Option<String>,
+    dry_run: bool,
+    interactive: bool,
+    bump: Option<BumpLevel>,
+    seed_projects: HashSet<String>,
+}
 
-    let args: Vec<String> = env::args().skip(1).collect();
-    if args.is_empty() {
+fn parse_args() -> Result<Args> {
+    let mut profile = None;
+    let mut dry_run = false;
+    let mut interactive = false;
+    let mut bump = None;
+    let mut seed_projects = HashSet::new();
+
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--profile" => profile = iter.next(),
+            "--dry-run" => dry_run = true,
+            "--interactive" => interactive = true,
+            "--bump" => {
+                let level = iter.next().context("--bump requires a value")?;
+                bump = Some(BumpLevel::parse(&level)?);
+            }
+            other => {
+                seed_projects.insert(other.to_string());
+            }
+        }
+    }
+
+    Ok(Args {
+        profile,
+        dry_run,
+        interactive,
+        bump,
+        seed_projects,
+    })
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    if args.seed_projects.is_empty() {
         println!("No projects specified for publishing. Exiting.");
         return Ok(());
     }
-    let seed_projects: HashSet<String> = args.into_iter().collect();
+    let seed_projects = args.seed_projects;
+
+    let dry_run = args.dry_run;
+    let interactive = args.interactive;
+    let bump = args.bump;
+    let config_path = env::current_dir()?.join(CONFIG_FILE_NAME);
+    let config = Config::load_or_init(&config_path)?;
+    let profile = config.profile(args.profile.as_deref())?.clone();
+
+    // A dry run neither publishes nor writes, so it does not need a token.
+    if !dry_run && env::var(&profile.token_env).is_err() {
+        return Err(anyhow!(
+            "Error: {} environment variable is not set.",
+            profile.token_env
+        ));
+    }
+
+    let state_path = env::current_dir()?.join(STATE_FILE_NAME);
+    let mut state = PublishState::load(&state_path)?;
 
     let standards_dir = env::current_dir()?.join("standards");
     let project_paths = find_sway_projects(&standards_dir)?;
@@ -91,74 +174,495 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let sorted_indices = toposort(&graph, None).map_err(|cycle| {
-        let node_index = cycle.node_id();
-        let project_name = graph.node_weight(node_index).unwrap();
-        anyhow!(
-            "A cycle was detected in the dependency graph involving '{}'",
-            project_name
-        )
-    })?;
+    // Group the packages into topological *layers*: every package in a layer is
+    // independent of the others in that same layer, so a layer can be published
+    // concurrently before moving on to the one that depends on it.
+    let layers = compute_layers(&graph, &node_map, &to_publish_names)?;
 
-    let sorted_projects: Vec<String> = sorted_indices
-        .iter()
-        .map(|&i| graph[i].clone())
-        .filter(|p| to_publish_names.contains(p))
-        .collect();
-
-    if sorted_projects.is_empty() {
+    if layers.is_empty() {
         println!("No projects to publish after filtering and sorting.");
         return Ok(());
     }
 
     println!("Publishing order determined:");
-    println!(" -> {}", sorted_projects.join(" -> "));
+    for (i, layer) in layers.iter().enumerate() {
+        println!(" layer {}: {}", i + 1, layer.join(", "));
+    }
     println!("{}", "-".repeat(30));
 
-    for project_name in sorted_projects {
-        println!("Publishing {}...", project_name);
-        let project_dir = standards_dir.join(&project_name);
-
-        let output = Command::new("forc")
-            .arg("publish")
-            .arg("--registry-url")
-            .arg("http://localhost:8080")
-            .current_dir(&project_dir)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
-            .context("Failed to execute 'forc publish'")?;
-
-        let stderr = String::from_utf8_lossy(&output.stderr);
-
-        if !output.status.success() {
-            if stderr.contains("already exists") {
-                println!("{} version already published, skipping.", project_name);
+    if interactive && !dry_run {
+        return run_interactive(
+            &layers,
+            &standards_dir,
+            &profile,
+            bump,
+            &mut state,
+            &mut all_packages_data,
+        );
+    }
+
+    for layer in &layers {
+        // Skip packages this exact version of which a previous run already
+        // published, so a resumed run picks up from the first incomplete entry.
+        let mut to_run = Vec::new();
+        // Remember each package's resolved (possibly bumped) version so it is the
+        // value propagated to dependents, rather than re-reading the doc — which
+        // under `--dry-run` deliberately skips the write and would otherwise emit
+        // a diff rewriting dependents to the stale, un-bumped version.
+        let mut resolved_versions: HashMap<String, String> = HashMap::new();
+        for name in layer {
+            let version = prepare_version(
+                &profile.registry_url,
+                name,
+                bump,
+                dry_run,
+                &state,
+                &mut all_packages_data,
+            )?;
+            resolved_versions.insert(name.clone(), version.clone());
+            if !dry_run && state.is_published(name, &version) {
+                println!("{} {} already published, resuming past it.", name, version);
             } else {
-                eprintln!("Error publishing {}:", project_name);
-                eprintln!("{}", stderr);
-                return Err(anyhow!("Failed to publish {}", project_name));
+                to_run.push(name.clone());
+            }
+        }
+
+        if dry_run {
+            for name in &to_run {
+                println!("[dry-run] would publish {}", name);
             }
         } else {
-            println!("Successfully published {}", project_name);
+            // Publish every remaining package in this layer concurrently. Each
+            // `forc publish` invocation is isolated to its own project dir/thread.
+            let outcomes: Vec<(String, Result<PublishOutcome>)> = std::thread::scope(|scope| {
+                let handles: Vec<_> = to_run
+                    .iter()
+                    .map(|name| {
+                        let project_dir = standards_dir.join(name);
+                        let profile = &profile;
+                        (
+                            name.clone(),
+                            scope.spawn(move || publish_package(name, &project_dir, profile)),
+                        )
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|(name, handle)| (name, handle.join().expect("publish thread panicked")))
+                    .collect()
+            });
+
+            for (project_name, outcome) in outcomes {
+                match outcome? {
+                    PublishOutcome::Published => {
+                        println!("Successfully published {}", project_name)
+                    }
+                    PublishOutcome::AlreadyExists => {
+                        println!("{} version already published, skipping.", project_name)
+                    }
+                }
+                let version = &resolved_versions[&project_name];
+                state.record(&project_name, version)?;
+            }
         }
 
-        // Clone the version string to release the immutable borrow on `all_packages_data`,
-        // allowing us to pass it mutably to `update_dependents`.
-        let published_version = all_packages_data[project_name.as_str()]
-            .0["project"]["version"]
-            .as_str()
-            .context("Could not find project version in Forc.toml")?
-            .to_string();
-        update_dependents(&project_name, &published_version, &mut all_packages_data)?;
+        // Apply every version rewrite from this completed layer before the next
+        // layer's builds begin, so each dependent's Forc.toml already reflects the
+        // published version by the time it is built. Under `--dry-run` the rewrites
+        // are applied in memory and surfaced as a diff rather than written to disk.
+        for project_name in layer {
+            let published_version = &resolved_versions[project_name];
+            update_dependents(
+                project_name,
+                published_version,
+                &mut all_packages_data,
+                dry_run,
+            )?;
+        }
     }
 
     println!("{}", "-".repeat(30));
-    println!("All standards published successfully!");
+    if dry_run {
+        println!("Dry run complete. No packages were published and no files were written.");
+    } else {
+        println!("All standards published successfully!");
+    }
+
+    Ok(())
+}
+
+/// Read a package's declared `[project].version` from its parsed Forc.toml.
+fn package_version(
+    all_packages_data: &BTreeMap<String, (DocumentMut, PathBuf)>,
+    name: &str,
+) -> Result<String> {
+    all_packages_data[name].0["project"]["version"]
+        .as_str()
+        .context("Could not find project version in Forc.toml")
+        .map(|v| v.to_string())
+}
+
+/// Bring a package's version in line with the registry before publishing.
+///
+/// Queries the registry for the latest published version of `name` and, when the
+/// local version already exists there and `--bump` was requested, increments it
+/// and writes the new version back into the package's own Forc.toml (unless this
+/// is a dry run). The resulting version is the one `update_dependents` should
+/// propagate, so it is returned to the caller.
+fn prepare_version(
+    registry_url: &str,
+    name: &str,
+    bump: Option<BumpLevel>,
+    dry_run: bool,
+    state: &PublishState,
+    all_packages_data: &mut BTreeMap<String, (DocumentMut, PathBuf)>,
+) -> Result<String> {
+    // If a prior run already published this package, reuse that exact version so
+    // a resumed `--bump` run skips it instead of computing a fresh bump every
+    // time. Writing it back keeps the in-memory/on-disk version aligned for
+    // `update_dependents`.
+    if !dry_run {
+        if let Some(recorded) = state.recorded_version(name) {
+            let version = Version::parse(&recorded)
+                .with_context(|| format!("Could not parse recorded version '{}' for {}", recorded, name))?;
+            set_package_version(all_packages_data, name, &version)?;
+            return Ok(recorded);
+        }
+    }
+
+    let local_str = package_version(all_packages_data, name)?;
+
+    // Without --bump there is nothing to reconcile against the registry, so skip
+    // the metadata query entirely: the baseline only ever shelled out to `forc
+    // publish`, and querying here is pure overhead that would make plain
+    // publishes and offline dry-run planning hard-fail if the endpoint is down.
+    let Some(level) = bump else {
+        return Ok(local_str);
+    };
+
+    let local = Version::parse(&local_str)
+        .with_context(|| format!("Could not parse version '{}' for {}", local_str, name))?;
+
+    let latest = match registry::latest_version(registry_url, name)? {
+        // A not-yet-registered package is a first publish with no bump.
+        None => return Ok(local_str),
+        Some(latest) => latest,
+    };
+
+    // Never downgrade. A local version already ahead of the registry needs no
+    // bump; but if it is exactly the bump we would apply, a prior run already
+    // wrote it and failed before recording — resume by publishing it as-is
+    // rather than wedging the run on a refuse-to-bump error.
+    if local > latest {
+        if local == bump_version(&latest, level) {
+            return Ok(local_str);
+        }
+        bail!(
+            "Refusing to bump {}: local version {} is already newer than the registry's {}",
+            name,
+            local,
+            latest
+        );
+    }
+
+    let bumped = bump_version(&latest, level);
+    println!("Bumping {} from {} to {} (latest on registry: {})", name, local, bumped, latest);
+
+    if !dry_run {
+        set_package_version(all_packages_data, name, &bumped)?;
+    }
+    Ok(bumped.to_string())
+}
+
+/// Increment `latest` by `level`, resetting the lower components and clearing any
+/// pre-release and build metadata, per the usual semver release-bump rules.
+fn bump_version(latest: &Version, level: BumpLevel) -> Version {
+    let mut bumped = match level {
+        BumpLevel::Major => Version::new(latest.major + 1, 0, 0),
+        BumpLevel::Minor => Version::new(latest.major, latest.minor + 1, 0),
+        BumpLevel::Patch => Version::new(latest.major, latest.minor, latest.patch + 1),
+    };
+    bumped.pre = semver::Prerelease::EMPTY;
+    bumped.build = semver::BuildMetadata::EMPTY;
+    bumped
+}
 
+/// Overwrite a package's own `[project].version`, both in memory and on disk.
+fn set_package_version(
+    all_packages_data: &mut BTreeMap<String, (DocumentMut, PathBuf)>,
+    name: &str,
+    version: &Version,
+) -> Result<()> {
+    let (data, toml_path) = all_packages_data
+        .get_mut(name)
+        .with_context(|| format!("Unknown package {}", name))?;
+    data["project"]["version"] = toml_edit::value(version.to_string());
+    fs::write(toml_path, data.to_string())
+        .with_context(|| format!("Failed to write bumped version for {}", name))?;
     Ok(())
 }
 
+/// Outcome of a single `forc publish` invocation.
+enum PublishOutcome {
+    Published,
+    AlreadyExists,
+}
+
+/// Publish one package, treating an "already exists" failure as a skip when the
+/// active profile allows it.
+fn publish_package(name: &str, project_dir: &Path, profile: &Profile) -> Result<PublishOutcome> {
+    let output = Command::new("forc")
+        .arg("publish")
+        .arg("--registry-url")
+        .arg(&profile.registry_url)
+        .current_dir(project_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .context("Failed to execute 'forc publish'")?;
+
+    if output.status.success() {
+        return Ok(PublishOutcome::Published);
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if profile.skip_existing && stderr.contains("already exists") {
+        Ok(PublishOutcome::AlreadyExists)
+    } else {
+        Err(anyhow!("Failed to publish {}:\n{}", name, stderr))
+    }
+}
+
+/// Live status of a node as the interactive run steps through the plan.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Queued,
+    Building,
+    Published,
+    SkippedAlreadyExists,
+    Skipped,
+    Failed,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Queued => "queued",
+            Status::Building => "building",
+            Status::Published => "published",
+            Status::SkippedAlreadyExists => "skipped-already-exists",
+            Status::Skipped => "skipped",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+/// Step through the plan one package at a time, confirming each with the
+/// maintainer, streaming `forc publish` output live, and keeping a status line
+/// for every node visible as the run progresses.
+fn run_interactive(
+    layers: &[Vec<String>],
+    standards_dir: &Path,
+    profile: &Profile,
+    bump: Option<BumpLevel>,
+    state: &mut PublishState,
+    all_packages_data: &mut BTreeMap<String, (DocumentMut, PathBuf)>,
+) -> Result<()> {
+    // Flatten the layers back into a single topological order for stepping.
+    let order: Vec<String> = layers.iter().flatten().cloned().collect();
+    let mut statuses: BTreeMap<String, Status> =
+        order.iter().map(|n| (n.clone(), Status::Queued)).collect();
+
+    for name in &order {
+        let version =
+            prepare_version(&profile.registry_url, name, bump, false, state, all_packages_data)?;
+
+        if state.is_published(name, &version) {
+            statuses.insert(name.clone(), Status::SkippedAlreadyExists);
+            print_statuses(&order, &statuses);
+            println!("{} {} already published, skipping.", name, version);
+            update_dependents(name, &version, all_packages_data, false)?;
+            continue;
+        }
+
+        print_statuses(&order, &statuses);
+        match prompt_action(name)? {
+            Action::Abort => {
+                println!("Aborting. Remaining packages were not published.");
+                return Ok(());
+            }
+            Action::Skip => {
+                statuses.insert(name.clone(), Status::Skipped);
+                println!("Skipping {}.", name);
+                continue;
+            }
+            Action::Confirm => {}
+        }
+
+        statuses.insert(name.clone(), Status::Building);
+        print_statuses(&order, &statuses);
+
+        let project_dir = standards_dir.join(name);
+        match publish_package_streaming(name, &project_dir, profile) {
+            Ok(PublishOutcome::Published) => {
+                statuses.insert(name.clone(), Status::Published);
+                println!("Successfully published {}", name);
+            }
+            Ok(PublishOutcome::AlreadyExists) => {
+                statuses.insert(name.clone(), Status::SkippedAlreadyExists);
+                println!("{} version already published, skipping.", name);
+            }
+            Err(e) => {
+                statuses.insert(name.clone(), Status::Failed);
+                print_statuses(&order, &statuses);
+                return Err(e);
+            }
+        }
+
+        state.record(name, &version)?;
+        update_dependents(name, &version, all_packages_data, false)?;
+    }
+
+    print_statuses(&order, &statuses);
+    println!("{}", "-".repeat(30));
+    println!("Interactive publish finished.");
+    Ok(())
+}
+
+/// Choice returned from the per-package confirm/skip/abort prompt.
+enum Action {
+    Confirm,
+    Skip,
+    Abort,
+}
+
+fn prompt_action(name: &str) -> Result<Action> {
+    use std::io::Write;
+    loop {
+        print!("Publish {}? [y = yes / s = skip / a = abort]: ", name);
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        match line.trim().to_lowercase().as_str() {
+            "" | "y" | "yes" => return Ok(Action::Confirm),
+            "s" | "skip" => return Ok(Action::Skip),
+            "a" | "abort" => return Ok(Action::Abort),
+            other => println!("Unrecognized input '{}'.", other),
+        }
+    }
+}
+
+/// Print a status line for every node in the plan.
+fn print_statuses(order: &[String], statuses: &BTreeMap<String, Status>) {
+    println!("{}", "-".repeat(30));
+    for name in order {
+        let status = statuses.get(name).copied().unwrap_or(Status::Queued);
+        println!("  [{:<22}] {}", status.label(), name);
+    }
+    println!("{}", "-".repeat(30));
+}
+
+/// Publish one package while streaming its stdout/stderr to the terminal. stderr
+/// is also captured so an "already exists" response can still be classified.
+fn publish_package_streaming(
+    name: &str,
+    project_dir: &Path,
+    profile: &Profile,
+) -> Result<PublishOutcome> {
+    use std::io::{BufRead, BufReader};
+
+    let mut child = Command::new("forc")
+        .arg("publish")
+        .arg("--registry-url")
+        .arg(&profile.registry_url)
+        .current_dir(project_dir)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute 'forc publish'")?;
+
+    let mut captured_stderr = String::new();
+    if let Some(stderr) = child.stderr.take() {
+        for line in BufReader::new(stderr).lines() {
+            let line = line?;
+            eprintln!("{}", line);
+            captured_stderr.push_str(&line);
+            captured_stderr.push('\n');
+        }
+    }
+
+    let status = child.wait().context("Failed to wait on 'forc publish'")?;
+    if status.success() {
+        return Ok(PublishOutcome::Published);
+    }
+
+    if profile.skip_existing && captured_stderr.contains("already exists") {
+        Ok(PublishOutcome::AlreadyExists)
+    } else {
+        Err(anyhow!("Failed to publish {}:\n{}", name, captured_stderr))
+    }
+}
+
+/// Split the publish set into topological layers using Kahn's algorithm: each
+/// round collects every not-yet-published node whose in-degree (within the set)
+/// is zero, emits them as one layer, and removes them from the graph.
+fn compute_layers(
+    graph: &DiGraph<String, ()>,
+    node_map: &HashMap<String, petgraph::graph::NodeIndex>,
+    to_publish_names: &HashSet<String>,
+) -> Result<Vec<Vec<String>>> {
+    use petgraph::Direction::{Incoming, Outgoing};
+
+    let in_set: HashSet<_> = to_publish_names
+        .iter()
+        .filter_map(|name| node_map.get(name).copied())
+        .collect();
+
+    let mut in_degree: HashMap<_, usize> = in_set
+        .iter()
+        .map(|&node| {
+            let deg = graph
+                .neighbors_directed(node, Incoming)
+                .filter(|dep| in_set.contains(dep))
+                .count();
+            (node, deg)
+        })
+        .collect();
+
+    let mut layers = Vec::new();
+    while !in_degree.is_empty() {
+        let mut layer: Vec<_> = in_degree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(&node, _)| node)
+            .collect();
+
+        if layer.is_empty() {
+            // Remaining nodes all have a dependency inside the set: a cycle.
+            let stuck = in_degree.keys().next().unwrap();
+            return Err(anyhow!(
+                "A cycle was detected in the dependency graph involving '{}'",
+                graph[*stuck]
+            ));
+        }
+
+        for &node in &layer {
+            in_degree.remove(&node);
+            for dependent in graph.neighbors_directed(node, Outgoing) {
+                if let Some(deg) = in_degree.get_mut(&dependent) {
+                    *deg -= 1;
+                }
+            }
+        }
+
+        // Sort within the layer for deterministic, readable output.
+        layer.sort_by(|a, b| graph[*a].cmp(&graph[*b]));
+        layers.push(layer.into_iter().map(|node| graph[node].clone()).collect());
+    }
+
+    Ok(layers)
+}
+
 fn find_sway_projects(directory: &Path) -> Result<Vec<PathBuf>> {
     let mut projects = vec![];
     for entry in fs::read_dir(directory)? {
@@ -175,29 +679,52 @@ fn update_dependents(
     published_package_name: &str,
     published_version: &str,
     all_packages_data: &mut BTreeMap<String, (DocumentMut, PathBuf)>,
+    dry_run: bool,
 ) -> Result<()> {
     for (package_name, (data, toml_path)) in all_packages_data.iter_mut() {
-        let mut dirty = false;
-        if let Some(dep) = data["dependencies"].get_mut(published_package_name) {
-            if let Some(dep_table) = dep.as_inline_table_mut() {
-                if dep_table.get("path").is_some() {
-                    println!(
-                        "Updating dependency '{}' in {}'s Forc.toml",
-                        published_package_name, package_name
-                    );
-                    dep_table.remove("path");
-                    dep_table.insert("version", published_version.into());
-                    dirty = true;
-                }
-            }
+        // Only dependents that still reference this package by `path` need a
+        // rewrite; check with an immutable borrow before snapshotting the file.
+        let has_path_dep = data
+            .get("dependencies")
+            .and_then(|deps| deps.get(published_package_name))
+            .and_then(|dep| dep.as_inline_table())
+            .is_some_and(|table| table.contains_key("path"));
+        if !has_path_dep {
+            continue;
         }
 
-        if dirty {
-            let new_toml_content = data.to_string();
-            fs::write(toml_path, new_toml_content).with_context(|| {
+        println!(
+            "Updating dependency '{}' in {}'s Forc.toml",
+            published_package_name, package_name
+        );
+
+        let before = data.to_string();
+        let dep_table = data["dependencies"][published_package_name]
+            .as_inline_table_mut()
+            .expect("dependency confirmed to be an inline table above");
+        dep_table.remove("path");
+        dep_table.insert("version", published_version.into());
+        let after = data.to_string();
+
+        if dry_run {
+            print_toml_diff(toml_path, &before, &after);
+        } else {
+            fs::write(toml_path, &after).with_context(|| {
                 format!("Failed to write updated Forc.toml for {}", package_name)
             })?;
         }
     }
     Ok(())
 }
+
+/// Print a unified diff of a single Forc.toml rewrite for `--dry-run` output.
+fn print_toml_diff(path: &Path, before: &str, after: &str) {
+    let label = path.display().to_string();
+    let diff = similar::TextDiff::from_lines(before, after);
+    print!(
+        "{}",
+        diff.unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{}", label), &format!("b/{}", label))
+    );
+}
@@ -0,0 +1,38 @@
+use anyhow::{Context, Result};
+use semver::Version;
+use serde::Deserialize;
+
+/// Shape of the registry's package-metadata response. Only the published version
+/// list is consumed here.
+#[derive(Debug, Deserialize)]
+struct PackageMetadata {
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+/// Query the registry for the highest version published under `name`, returning
+/// `None` when the package has never been registered.
+pub fn latest_version(registry_url: &str, name: &str) -> Result<Option<Version>> {
+    let url = format!("{}/api/v1/packages/{}", registry_url.trim_end_matches('/'), name);
+    let response = reqwest::blocking::get(&url)
+        .with_context(|| format!("Failed to query registry for {}", name))?;
+
+    // A not-yet-registered package reads as a first publish rather than an error.
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let response = response
+        .error_for_status()
+        .with_context(|| format!("Registry returned an error for {}", name))?;
+
+    let metadata: PackageMetadata = response
+        .json()
+        .with_context(|| format!("Failed to decode registry metadata for {}", name))?;
+
+    let latest = metadata
+        .versions
+        .iter()
+        .filter_map(|v| Version::parse(v).ok())
+        .max();
+    Ok(latest)
+}
@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the resume-state file written next to the invocation's working dir.
+pub const STATE_FILE_NAME: &str = ".publish-state.json";
+
+/// A `(package, version)` pair that has been confirmed published.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PublishedPackage {
+    pub name: String,
+    pub version: String,
+}
+
+/// Persisted record of which packages have already been published, used to skip
+/// completed work when resuming after a mid-pipeline failure.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PublishState {
+    #[serde(skip)]
+    path: PathBuf,
+    published: BTreeSet<PublishedPackage>,
+}
+
+impl PublishState {
+    /// Load state from `path`, returning an empty (but `path`-bound) state when
+    /// no file exists yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(PublishState {
+                path: path.to_path_buf(),
+                ..Default::default()
+            });
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read state at {}", path.display()))?;
+        let mut state: PublishState = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse state at {}", path.display()))?;
+        state.path = path.to_path_buf();
+        Ok(state)
+    }
+
+    /// Has this exact `(name, version)` already been published?
+    pub fn is_published(&self, name: &str, version: &str) -> bool {
+        self.published.contains(&PublishedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+        })
+    }
+
+    /// The version already published for `name` on a prior run, if any. Used to
+    /// make a resumed run idempotent even when `--bump` would otherwise compute a
+    /// fresh version each time.
+    pub fn recorded_version(&self, name: &str) -> Option<String> {
+        self.published
+            .iter()
+            .find(|pkg| pkg.name == name)
+            .map(|pkg| pkg.version.clone())
+    }
+
+    /// Record a successful publish and flush the state to disk.
+    pub fn record(&mut self, name: &str, version: &str) -> Result<()> {
+        self.published.insert(PublishedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+        });
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let rendered =
+            serde_json::to_string_pretty(self).context("Failed to serialize publish state")?;
+        fs::write(&self.path, rendered)
+            .with_context(|| format!("Failed to write state to {}", self.path.display()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! Reusable end-to-end test harness.
+//!
+//! Unlike the unit-style `setup()` helpers, which spin up an in-process node via
+//! `launch_custom_provider_and_get_wallets`, this harness brings up a real
+//! `fuel-core` node and a local `forc` registry as `testcontainers` containers on
+//! a shared Docker network. It wires a `fuels` [`Provider`] to the node's mapped
+//! port and offers a builder that publishes a standard to the registry and then
+//! deploys one of the example binaries against the live node.
+//!
+//! These tests need a working Docker daemon and are therefore marked `#[ignore]`
+//! so they are skipped by a plain `cargo test` and opted into explicitly with
+//! `cargo test -- --ignored`.
+
+use fuels::accounts::provider::Provider;
+use std::process::Command;
+use testcontainers::{
+    core::{ContainerPort, WaitFor},
+    runners::AsyncRunner,
+    ContainerAsync, Image, ImageExt,
+};
+
+/// Docker network the node and registry share so they can reach one another by
+/// container alias.
+const NETWORK: &str = "sway-standards-e2e";
+
+/// GraphQL port exposed by `fuel-core`.
+const FUEL_CORE_PORT: ContainerPort = ContainerPort::Tcp(4000);
+/// HTTP port exposed by the local `forc` registry.
+const REGISTRY_PORT: ContainerPort = ContainerPort::Tcp(8080);
+
+/// A `fuel-core` node image configured to run an ephemeral in-memory chain.
+#[derive(Debug, Default, Clone)]
+pub struct FuelCoreImage;
+
+impl Image for FuelCoreImage {
+    fn name(&self) -> &str {
+        "ghcr.io/fuellabs/fuel-core"
+    }
+
+    fn tag(&self) -> &str {
+        "v0.40.0"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        // fuel-core logs this line once the GraphQL endpoint is accepting calls.
+        vec![WaitFor::message_on_stderr("Binding GraphQL provider to")]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[FUEL_CORE_PORT]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<std::borrow::Cow<'_, str>>> {
+        ["run", "--db-type", "in-memory", "--ip", "0.0.0.0", "--port", "4000"]
+    }
+}
+
+/// A local `forc` registry image the publisher pushes standards to.
+#[derive(Debug, Default, Clone)]
+pub struct ForcRegistryImage;
+
+impl Image for ForcRegistryImage {
+    fn name(&self) -> &str {
+        "ghcr.io/fuellabs/forc-registry"
+    }
+
+    fn tag(&self) -> &str {
+        "latest"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::message_on_stdout("registry listening")]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[REGISTRY_PORT]
+    }
+}
+
+/// A running node + registry pair plus a [`Provider`] bound to the node.
+pub struct StandardHarness {
+    _node: ContainerAsync<FuelCoreImage>,
+    _registry: ContainerAsync<ForcRegistryImage>,
+    provider: Provider,
+    registry_url: String,
+}
+
+impl StandardHarness {
+    /// Bring up the node and registry on a shared network and connect a provider
+    /// to the node's mapped GraphQL port.
+    pub async fn start() -> anyhow::Result<Self> {
+        let node = FuelCoreImage
+            .with_network(NETWORK)
+            .with_container_name("fuel-core")
+            .start()
+            .await?;
+        let registry = ForcRegistryImage
+            .with_network(NETWORK)
+            .with_container_name("forc-registry")
+            .start()
+            .await?;
+
+        let node_port = node.get_host_port_ipv4(FUEL_CORE_PORT).await?;
+        let provider = Provider::connect(format!("127.0.0.1:{node_port}")).await?;
+
+        let registry_port = registry.get_host_port_ipv4(REGISTRY_PORT).await?;
+        let registry_url = format!("http://127.0.0.1:{registry_port}");
+
+        Ok(Self {
+            _node: node,
+            _registry: registry,
+            provider,
+            registry_url,
+        })
+    }
+
+    /// The provider connected to the ephemeral node.
+    pub fn provider(&self) -> &Provider {
+        &self.provider
+    }
+
+    /// Publish the standard in `project_dir` to the harness registry.
+    pub fn publish(&self, project_dir: &str) -> anyhow::Result<()> {
+        let status = Command::new("forc")
+            .arg("publish")
+            .arg("--registry-url")
+            .arg(&self.registry_url)
+            .current_dir(project_dir)
+            .status()?;
+        anyhow::ensure!(status.success(), "forc publish failed for {project_dir}");
+        Ok(())
+    }
+}
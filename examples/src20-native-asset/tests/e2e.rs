@@ -0,0 +1,57 @@
+//! End-to-end test that publishes the SRC-20 standard to an ephemeral registry
+//! and deploys the example contract against a live `fuel-core` node.
+//!
+//! Requires Docker; run with `cargo test -- --ignored`.
+
+// Pulled in by path so cargo does not compile the shared harness as its own
+// (test-less) integration-test binary.
+#[path = "common/mod.rs"]
+mod harness;
+
+use fuels::{
+    prelude::{abigen, Contract, LoadConfiguration, TxPolicies},
+    types::transaction_builders::VariableOutputPolicy,
+};
+use harness::StandardHarness;
+
+abigen!(Contract(
+    name = "SingleAsset",
+    abi = "./single_asset/out/release/single_src20_asset-abi.json"
+));
+
+const SINGLE_ASSET_CONTRACT_BINARY_PATH: &str =
+    "./single_asset/out/release/single_src20_asset.bin";
+
+#[tokio::test]
+#[ignore = "requires a running Docker daemon"]
+async fn publishes_and_deploys_single_asset() {
+    let harness = StandardHarness::start().await.unwrap();
+
+    // Publish the standard to the ephemeral registry before deploying a contract
+    // that depends on it.
+    harness.publish("./single_asset").unwrap();
+
+    let account =
+        fuels::accounts::wallet::WalletUnlocked::new_random(Some(harness.provider().clone()));
+
+    let id = Contract::load_from(
+        SINGLE_ASSET_CONTRACT_BINARY_PATH,
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&account, TxPolicies::default())
+    .await
+    .unwrap();
+
+    let instance = SingleAsset::new(id.clone(), account);
+
+    let total_assets = instance
+        .methods()
+        .total_assets()
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(total_assets.value, 1);
+}